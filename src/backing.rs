@@ -0,0 +1,217 @@
+/// Abstracts the dynamically-sized store backing the non-static portion of [`crate::Vec`].
+///
+/// This allows swapping in alternative backends (for example an inline `SmallVec`, or an arena
+/// allocator) without changing `Vec`'s behavior.
+///
+/// Blanket implementations are provided for [`std::vec::Vec`] and [`std::collections::VecDeque`].
+/// `Vec::new`/`Vec::with_capacity`/`Vec::from_elem` (and so every form of [`crate::vec!`]) are only
+/// defined for the default `std::vec::Vec` backing, since Rust's type parameter defaults don't
+/// participate in inference; every other `Vec` method is generic over any `Backing`, and a `Vec`
+/// with a non-default backing can be constructed via `TryFrom`.
+pub trait Backing<T>: Default + Extend<T> + IntoIterator<Item = T> {
+    /// An iterator over references to the items in the backing store.
+    type Iter<'a>: Iterator<Item = &'a T>
+    where
+        Self: 'a,
+        T: 'a;
+
+    /// An iterator over mutable references to the items in the backing store.
+    type IterMut<'a>: Iterator<Item = &'a mut T>
+    where
+        Self: 'a,
+        T: 'a;
+
+    /// Constructs an empty backing store with at least the given capacity pre-allocated.
+    fn with_capacity(capacity: usize) -> Self;
+
+    /// The number of items the backing store can hold without reallocating.
+    fn capacity(&self) -> usize;
+
+    /// The number of items currently stored.
+    fn len(&self) -> usize;
+
+    /// Returns `true` if the backing store holds no items.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Appends an item to the back of the backing store.
+    fn push(&mut self, item: T);
+
+    /// Removes and returns the last item, if any.
+    fn pop(&mut self) -> Option<T>;
+
+    /// Returns a reference to the item at `index`, if any.
+    fn get(&self, index: usize) -> Option<&T>;
+
+    /// Returns a mutable reference to the item at `index`, if any.
+    fn get_mut(&mut self, index: usize) -> Option<&mut T>;
+
+    /// Inserts an item at position `index`, shifting all items after it to the right.
+    ///
+    /// # Panics
+    /// Panics if `index > len`.
+    fn insert(&mut self, index: usize, item: T);
+
+    /// Removes and returns the item at position `index`, shifting all items after it to the left.
+    ///
+    /// # Panics
+    /// Panics if `index >= len`.
+    fn remove(&mut self, index: usize) -> T;
+
+    /// Removes and returns the item at position `index`, replacing it with the last item.
+    ///
+    /// # Panics
+    /// Panics if `index >= len`.
+    fn swap_remove(&mut self, index: usize) -> T;
+
+    /// Retains only the items for which `keep` returns `true`.
+    fn retain<F: FnMut(&T) -> bool>(&mut self, keep: F);
+
+    /// Shortens the backing store, keeping the first `len` items and dropping the rest.
+    ///
+    /// If `len` is greater than or equal to the backing store's current length, this has no effect.
+    fn truncate(&mut self, len: usize);
+
+    /// Returns an iterator over references to the items in the backing store.
+    fn iter(&self) -> Self::Iter<'_>;
+
+    /// Returns an iterator over mutable references to the items in the backing store.
+    fn iter_mut(&mut self) -> Self::IterMut<'_>;
+}
+
+impl<T> Backing<T> for std::vec::Vec<T> {
+    type Iter<'a>
+        = std::slice::Iter<'a, T>
+    where
+        T: 'a;
+
+    type IterMut<'a>
+        = std::slice::IterMut<'a, T>
+    where
+        T: 'a;
+
+    fn with_capacity(capacity: usize) -> Self {
+        std::vec::Vec::with_capacity(capacity)
+    }
+
+    fn capacity(&self) -> usize {
+        std::vec::Vec::capacity(self)
+    }
+
+    fn len(&self) -> usize {
+        std::vec::Vec::len(self)
+    }
+
+    fn push(&mut self, item: T) {
+        std::vec::Vec::push(self, item);
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        std::vec::Vec::pop(self)
+    }
+
+    fn get(&self, index: usize) -> Option<&T> {
+        <[T]>::get(self, index)
+    }
+
+    fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        <[T]>::get_mut(self, index)
+    }
+
+    fn insert(&mut self, index: usize, item: T) {
+        std::vec::Vec::insert(self, index, item);
+    }
+
+    fn remove(&mut self, index: usize) -> T {
+        std::vec::Vec::remove(self, index)
+    }
+
+    fn swap_remove(&mut self, index: usize) -> T {
+        std::vec::Vec::swap_remove(self, index)
+    }
+
+    fn retain<F: FnMut(&T) -> bool>(&mut self, mut keep: F) {
+        std::vec::Vec::retain(self, |item| keep(item));
+    }
+
+    fn truncate(&mut self, len: usize) {
+        std::vec::Vec::truncate(self, len);
+    }
+
+    fn iter(&self) -> Self::Iter<'_> {
+        <[T]>::iter(self)
+    }
+
+    fn iter_mut(&mut self) -> Self::IterMut<'_> {
+        <[T]>::iter_mut(self)
+    }
+}
+
+impl<T> Backing<T> for std::collections::VecDeque<T> {
+    type Iter<'a>
+        = std::collections::vec_deque::Iter<'a, T>
+    where
+        T: 'a;
+
+    type IterMut<'a>
+        = std::collections::vec_deque::IterMut<'a, T>
+    where
+        T: 'a;
+
+    fn with_capacity(capacity: usize) -> Self {
+        std::collections::VecDeque::with_capacity(capacity)
+    }
+
+    fn capacity(&self) -> usize {
+        std::collections::VecDeque::capacity(self)
+    }
+
+    fn len(&self) -> usize {
+        std::collections::VecDeque::len(self)
+    }
+
+    fn push(&mut self, item: T) {
+        std::collections::VecDeque::push_back(self, item);
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        std::collections::VecDeque::pop_back(self)
+    }
+
+    fn get(&self, index: usize) -> Option<&T> {
+        std::collections::VecDeque::get(self, index)
+    }
+
+    fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        std::collections::VecDeque::get_mut(self, index)
+    }
+
+    fn insert(&mut self, index: usize, item: T) {
+        std::collections::VecDeque::insert(self, index, item);
+    }
+
+    fn remove(&mut self, index: usize) -> T {
+        std::collections::VecDeque::remove(self, index).expect("index out of bounds")
+    }
+
+    fn swap_remove(&mut self, index: usize) -> T {
+        std::collections::VecDeque::swap_remove_back(self, index).expect("index out of bounds")
+    }
+
+    fn retain<F: FnMut(&T) -> bool>(&mut self, mut keep: F) {
+        std::collections::VecDeque::retain(self, |item| keep(item));
+    }
+
+    fn truncate(&mut self, len: usize) {
+        std::collections::VecDeque::truncate(self, len);
+    }
+
+    fn iter(&self) -> Self::Iter<'_> {
+        std::collections::VecDeque::iter(self)
+    }
+
+    fn iter_mut(&mut self) -> Self::IterMut<'_> {
+        std::collections::VecDeque::iter_mut(self)
+    }
+}
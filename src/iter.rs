@@ -0,0 +1,52 @@
+/// An iterator that is guaranteed to yield at least one item.
+///
+/// Chains a statically known first item with an iterator over the remaining, dynamically
+/// stored items. Because the data structures in this crate can never be empty, the first item
+/// is always available via [`NonEmptyIter::peek_first`], without needing an `Option`.
+pub struct NonEmptyIter<Item, I> {
+    first: Option<Item>,
+    dynamic: I,
+}
+
+impl<Item, I> NonEmptyIter<Item, I>
+where
+    I: Iterator<Item = Item>,
+{
+    pub(crate) fn new(first: Item, dynamic: I) -> Self {
+        Self {
+            first: Some(first),
+            dynamic,
+        }
+    }
+
+    /// Returns a reference to the first item this iterator will yield.
+    ///
+    /// Because the wrapped data structure is guaranteed to be non-empty, this is always
+    /// available before iteration begins, with no need for an `Option`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called after the first item has already been consumed via [`Iterator::next`].
+    pub fn peek_first(&self) -> &Item {
+        self.first
+            .as_ref()
+            .expect("peek_first called after the first item was already consumed")
+    }
+}
+
+impl<Item, I> Iterator for NonEmptyIter<Item, I>
+where
+    I: Iterator<Item = Item>,
+{
+    type Item = Item;
+
+    fn next(&mut self) -> Option<Item> {
+        self.first.take().or_else(|| self.dynamic.next())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.dynamic.size_hint();
+        let extra = usize::from(self.first.is_some());
+        (lower + extra, upper.map(|upper| upper + extra))
+    }
+}
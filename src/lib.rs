@@ -54,8 +54,12 @@
 #[cfg(not(any(feature = "std")))]
 compile_error!("The `std` feature is currently required. Adding support for `no-std` is backwards compatible! If you need this, a PR is extremely welcome!");
 
+mod backing;
 mod capacity;
+mod iter;
 mod structures;
 
+pub use backing::*;
 pub use capacity::*;
+pub use iter::*;
 pub use structures::*;
@@ -0,0 +1,22 @@
+//! Non-empty implementations of common data structures.
+
+mod std;
+
+pub use std::*;
+
+/// Errors arising from fallible conversions into non-empty data structures.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum TryFromError {
+    /// The source collection contained no elements, so it cannot be converted into a non-empty one.
+    SourceEmpty,
+}
+
+impl ::std::fmt::Display for TryFromError {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        match self {
+            Self::SourceEmpty => write!(f, "source collection is empty"),
+        }
+    }
+}
+
+impl ::std::error::Error for TryFromError {}
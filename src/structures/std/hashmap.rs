@@ -19,5 +19,210 @@ pub struct HashMap<K, V> {
     dynamic: std::collections::HashMap<K, V>,
 }
 
-/// This structure stores a single item statically.
-type Capacity = crate::Capacity<1>;
+impl<K, V> PartialEq for HashMap<K, V>
+where
+    K: Eq + std::hash::Hash,
+    V: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        // Can't compare `first`/`dynamic` positionally: the same set of pairs can be split
+        // across the two fields differently depending on insertion/removal history.
+        self.len() == other.len() && self.iter().all(|(k, v)| other.get(k) == Some(v))
+    }
+}
+
+use crate::{NonEmptyIter, TryFromError};
+
+impl<K, V> HashMap<K, V>
+where
+    K: Eq + std::hash::Hash,
+{
+    /// Constructs a new instance with a single key-value pair.
+    ///
+    /// # Examples
+    /// ```
+    /// let map = unempty::HashMap::new("a", 1);
+    /// assert_eq!(map.get(&"a"), Some(&1));
+    /// ```
+    pub fn new(key: K, value: V) -> Self {
+        Self {
+            first: (key, value),
+            dynamic: Default::default(),
+        }
+    }
+
+    /// Inserts a key-value pair, returning the previous value if the key was already present.
+    ///
+    /// # Examples
+    /// ```
+    /// let mut map = unempty::HashMap::new("a", 1);
+    /// assert_eq!(map.insert("a", 2), Some(1));
+    /// assert_eq!(map.insert("b", 3), None);
+    /// ```
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        if self.first.0 == key {
+            Some(std::mem::replace(&mut self.first.1, value))
+        } else {
+            self.dynamic.insert(key, value)
+        }
+    }
+
+    /// Returns a reference to the value corresponding to `key`, if present.
+    ///
+    /// # Examples
+    /// ```
+    /// let map = unempty::HashMap::new("a", 1);
+    /// assert_eq!(map.get(&"a"), Some(&1));
+    /// assert_eq!(map.get(&"b"), None);
+    /// ```
+    pub fn get(&self, key: &K) -> Option<&V> {
+        if self.first.0 == *key {
+            Some(&self.first.1)
+        } else {
+            self.dynamic.get(key)
+        }
+    }
+
+    /// Returns a mutable reference to the value corresponding to `key`, if present.
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        if self.first.0 == *key {
+            Some(&mut self.first.1)
+        } else {
+            self.dynamic.get_mut(key)
+        }
+    }
+
+    /// Returns `true` if the map contains a value for `key`.
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.first.0 == *key || self.dynamic.contains_key(key)
+    }
+
+    /// Returns the number of key-value pairs in the map. Includes both the static and dynamic portions.
+    pub fn len(&self) -> usize {
+        self.dynamic.len() + 1
+    }
+
+    /// Returns `true` if the map contains no elements.
+    /// This method _always_ returns `false`, because by definition an `unempty::HashMap` cannot be empty.
+    /// This method is included for API completeness and to make Clippy happy.
+    pub fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Removes a key from the map, returning its value if it was present, consuming `self`.
+    ///
+    /// Removing the key stored in the static first slot promotes an arbitrary remaining entry
+    /// into its place.
+    ///
+    /// # Consuming self
+    ///
+    /// Since removal may remove the only remaining pair, this consumes the map and returns
+    /// `None` if no pairs remain, following the same pattern as [`crate::Vec::pop`].
+    ///
+    /// # Examples
+    /// ```
+    /// let map = unempty::HashMap::new("a", 1);
+    /// let (map, value) = map.remove(&"a");
+    /// assert_eq!(value, Some(1));
+    /// assert_eq!(map, None);
+    /// ```
+    pub fn remove(mut self, key: &K) -> (Option<Self>, Option<V>) {
+        if self.first.0 == *key {
+            let old_value = self.first.1;
+            let dynamic = std::mem::take(&mut self.dynamic);
+            let mut dynamic = dynamic.into_iter();
+            match dynamic.next() {
+                Some((promoted_key, promoted_value)) => {
+                    self.first = (promoted_key, promoted_value);
+                    self.dynamic = dynamic.collect();
+                    (Some(self), Some(old_value))
+                }
+                None => (None, Some(old_value)),
+            }
+        } else {
+            let removed = self.dynamic.remove(key);
+            (Some(self), removed)
+        }
+    }
+}
+
+impl<K, V> TryFrom<std::collections::HashMap<K, V>> for HashMap<K, V>
+where
+    K: Eq + std::hash::Hash,
+{
+    type Error = TryFromError;
+
+    fn try_from(map: std::collections::HashMap<K, V>) -> Result<Self, Self::Error> {
+        let mut iter = map.into_iter();
+        match iter.next() {
+            Some((first_key, first_value)) => {
+                let mut result = Self::new(first_key, first_value);
+                result.dynamic.extend(iter);
+                Ok(result)
+            }
+            None => Err(TryFromError::SourceEmpty),
+        }
+    }
+}
+
+impl<K, V> HashMap<K, V> {
+    /// Returns the statically stored key-value pair.
+    ///
+    /// Since an `unempty::HashMap` is guaranteed to never be empty, a pair is always available
+    /// without needing an `Option`. There is no guarantee about which pair this is: it is the
+    /// pair the map was constructed with, or, after that pair's key is removed, an arbitrary
+    /// remaining pair promoted into its place.
+    pub fn first(&self) -> (&K, &V) {
+        (&self.first.0, &self.first.1)
+    }
+
+    /// Returns the statically stored key-value pair, with a mutable reference to the value.
+    ///
+    /// Since an `unempty::HashMap` is guaranteed to never be empty, a pair is always available
+    /// without needing an `Option`. There is no guarantee about which pair this is: it is the
+    /// pair the map was constructed with, or, after that pair's key is removed, an arbitrary
+    /// remaining pair promoted into its place.
+    pub fn first_mut(&mut self) -> (&K, &mut V) {
+        (&self.first.0, &mut self.first.1)
+    }
+
+    /// Returns an iterator over references to the key-value pairs, with no guaranteed order
+    /// beyond the statically stored pair being visited first.
+    pub fn iter(&self) -> NonEmptyIter<(&K, &V), std::collections::hash_map::Iter<'_, K, V>> {
+        NonEmptyIter::new((&self.first.0, &self.first.1), self.dynamic.iter())
+    }
+
+    /// Returns an iterator over the key-value pairs with mutable references to the values.
+    pub fn iter_mut(
+        &mut self,
+    ) -> NonEmptyIter<(&K, &mut V), std::collections::hash_map::IterMut<'_, K, V>> {
+        NonEmptyIter::new((&self.first.0, &mut self.first.1), self.dynamic.iter_mut())
+    }
+}
+
+impl<K, V> IntoIterator for HashMap<K, V> {
+    type Item = (K, V);
+    type IntoIter = NonEmptyIter<(K, V), std::collections::hash_map::IntoIter<K, V>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        NonEmptyIter::new(self.first, self.dynamic.into_iter())
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a HashMap<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = NonEmptyIter<(&'a K, &'a V), std::collections::hash_map::Iter<'a, K, V>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a mut HashMap<K, V> {
+    type Item = (&'a K, &'a mut V);
+    type IntoIter = NonEmptyIter<(&'a K, &'a mut V), std::collections::hash_map::IterMut<'a, K, V>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
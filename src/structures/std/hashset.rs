@@ -19,5 +19,173 @@ pub struct HashSet<T> {
     dynamic: std::collections::HashSet<T>,
 }
 
-/// This structure stores a single item statically.
-type Capacity = crate::Capacity<1>;
+impl<T> PartialEq for HashSet<T>
+where
+    T: Eq + std::hash::Hash,
+{
+    fn eq(&self, other: &Self) -> bool {
+        // Can't compare `first`/`dynamic` positionally: the same set of items can be split
+        // across the two fields differently depending on insertion/removal history.
+        self.len() == other.len() && self.iter().all(|item| other.contains(item))
+    }
+}
+
+use crate::{NonEmptyIter, TryFromError};
+
+impl<T> HashSet<T>
+where
+    T: Eq + std::hash::Hash,
+{
+    /// Constructs a new instance with a single item.
+    ///
+    /// # Examples
+    /// ```
+    /// let set = unempty::HashSet::new("a");
+    /// assert!(set.contains(&"a"));
+    /// ```
+    pub fn new(first: T) -> Self {
+        Self {
+            first,
+            dynamic: Default::default(),
+        }
+    }
+
+    /// Inserts a value, returning `true` if it was not already present.
+    ///
+    /// # Examples
+    /// ```
+    /// let mut set = unempty::HashSet::new("a");
+    /// assert!(set.insert("b"));
+    /// assert!(!set.insert("a"));
+    /// ```
+    pub fn insert(&mut self, value: T) -> bool {
+        if self.first == value {
+            false
+        } else {
+            self.dynamic.insert(value)
+        }
+    }
+
+    /// Returns `true` if the set contains `value`.
+    ///
+    /// # Examples
+    /// ```
+    /// let set = unempty::HashSet::new("a");
+    /// assert!(set.contains(&"a"));
+    /// assert!(!set.contains(&"b"));
+    /// ```
+    pub fn contains(&self, value: &T) -> bool {
+        self.first == *value || self.dynamic.contains(value)
+    }
+
+    /// Returns the number of items in the set. Includes both the static and dynamic portions.
+    pub fn len(&self) -> usize {
+        self.dynamic.len() + 1
+    }
+
+    /// Returns `true` if the set contains no elements.
+    /// This method _always_ returns `false`, because by definition an `unempty::HashSet` cannot be empty.
+    /// This method is included for API completeness and to make Clippy happy.
+    pub fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Removes `value` from the set, returning whether it was present, consuming `self`.
+    ///
+    /// Removing the statically stored item promotes an arbitrary remaining item into its place.
+    ///
+    /// # Consuming self
+    ///
+    /// Since removal may remove the only remaining item, this consumes the set and returns
+    /// `None` if no items remain, following the same pattern as [`crate::Vec::pop`].
+    ///
+    /// # Examples
+    /// ```
+    /// let set = unempty::HashSet::new("a");
+    /// let (set, removed) = set.remove(&"a");
+    /// assert!(removed);
+    /// assert_eq!(set, None);
+    /// ```
+    pub fn remove(mut self, value: &T) -> (Option<Self>, bool) {
+        if self.first == *value {
+            let dynamic = std::mem::take(&mut self.dynamic);
+            let mut dynamic = dynamic.into_iter();
+            match dynamic.next() {
+                Some(promoted) => {
+                    self.first = promoted;
+                    self.dynamic = dynamic.collect();
+                    (Some(self), true)
+                }
+                None => (None, true),
+            }
+        } else {
+            let removed = self.dynamic.remove(value);
+            (Some(self), removed)
+        }
+    }
+}
+
+impl<T> TryFrom<std::collections::HashSet<T>> for HashSet<T>
+where
+    T: Eq + std::hash::Hash,
+{
+    type Error = TryFromError;
+
+    fn try_from(set: std::collections::HashSet<T>) -> Result<Self, Self::Error> {
+        let mut iter = set.into_iter();
+        match iter.next() {
+            Some(first) => {
+                let mut result = Self::new(first);
+                result.dynamic.extend(iter);
+                Ok(result)
+            }
+            None => Err(TryFromError::SourceEmpty),
+        }
+    }
+}
+
+impl<T> HashSet<T> {
+    /// Returns the statically stored item.
+    ///
+    /// Since an `unempty::HashSet` is guaranteed to never be empty, an item is always available
+    /// without needing an `Option`. There is no guarantee about which item this is: it is the
+    /// item the set was constructed with, or, after that item is removed, an arbitrary
+    /// remaining item promoted into its place.
+    pub fn first(&self) -> &T {
+        &self.first
+    }
+
+    /// Returns a mutable reference to the statically stored item.
+    ///
+    /// Since an `unempty::HashSet` is guaranteed to never be empty, an item is always available
+    /// without needing an `Option`. There is no guarantee about which item this is: it is the
+    /// item the set was constructed with, or, after that item is removed, an arbitrary
+    /// remaining item promoted into its place.
+    pub fn first_mut(&mut self) -> &mut T {
+        &mut self.first
+    }
+
+    /// Returns an iterator over references to the items, with no guaranteed order beyond the
+    /// statically stored item being visited first.
+    pub fn iter(&self) -> NonEmptyIter<&T, std::collections::hash_set::Iter<'_, T>> {
+        NonEmptyIter::new(&self.first, self.dynamic.iter())
+    }
+}
+
+impl<T> IntoIterator for HashSet<T> {
+    type Item = T;
+    type IntoIter = NonEmptyIter<T, std::collections::hash_set::IntoIter<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        NonEmptyIter::new(self.first, self.dynamic.into_iter())
+    }
+}
+
+impl<'a, T> IntoIterator for &'a HashSet<T> {
+    type Item = &'a T;
+    type IntoIter = NonEmptyIter<&'a T, std::collections::hash_set::Iter<'a, T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
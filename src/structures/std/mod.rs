@@ -0,0 +1,9 @@
+//! Non-empty data structures backed by `std` collections.
+
+mod hashmap;
+mod hashset;
+mod vec;
+
+pub use hashmap::*;
+pub use hashset::*;
+pub use vec::*;
@@ -1,6 +1,6 @@
 use std::{
     collections::VecDeque,
-    ops::{Index, IndexMut},
+    ops::{Index, IndexMut, RangeBounds},
 };
 
 /// `Vec` stores a single item in the data structure.
@@ -12,12 +12,18 @@ type Capacity = crate::Capacity<1>;
 /// ```
 /// let v = unempty::vec![1, 2, 3];
 /// let v = unempty::vec![1];
+/// let v = unempty::vec![0; 3];
 /// ```
 #[macro_export]
 macro_rules! vec {
     ($item:expr) => {{
         unempty::Vec::new($item)
     }};
+    ($elem:expr; $n:expr) => {{
+        let n = core::num::NonZeroUsize::new($n)
+            .expect("unempty::vec! repeat count must be greater than zero");
+        unempty::Vec::from_elem($elem, n)
+    }};
     ($initial:expr, $( $additional:expr ),*) => {{
         let mut v = unempty::Vec::new($initial);
         $(
@@ -27,13 +33,19 @@ macro_rules! vec {
     }};
 }
 
-use crate::TryFromError;
+use crate::{Backing, NonEmptyIter, TryFromError};
 
 /// A non-empty vector of items.
 ///
-/// The first entry is statically stored. Additional items are dynamically stored with
-/// [`std::vec::Vec<T>`]; for memory and performance characteristics please review the documentation
-/// for that module and type.
+/// The first entry is statically stored. Additional items are dynamically stored with a
+/// pluggable backing store `B` (see [`Backing`]), which defaults to [`std::vec::Vec<T>`]; for
+/// memory and performance characteristics of the default backing please review the
+/// documentation for that type.
+///
+/// Almost every method is generic over any [`Backing`]. The only exceptions are
+/// [`Vec::new`], [`Vec::with_capacity`], and [`Vec::from_elem`], which are only defined for the
+/// default backing (see [`Backing`]'s docs for why); construct a `Vec` with a different backing
+/// via `TryFrom` instead.
 ///
 /// # Completeness
 ///
@@ -45,15 +57,22 @@ use crate::TryFromError;
 /// Does not currently support customizable allocators, nightly features, or unstable features.
 /// If any of these are desired, please submit a PR for the parts you need!
 #[derive(Clone, PartialEq, Eq, Debug)]
-pub struct Vec<T> {
+pub struct Vec<T, B = std::vec::Vec<T>> {
     first: T,
-    dynamic: std::vec::Vec<T>,
+    dynamic: B,
 }
 
-impl<T> Vec<T> {
+impl<T> Vec<T, std::vec::Vec<T>> {
     /// Efficiently constructs a new instance with a single item.
     ///
-    /// The underlying [`std::vec::Vec`] does not allocate unless more items are pushed.
+    /// The underlying backing store does not allocate unless more items are pushed.
+    ///
+    /// This (and [`Vec::with_capacity`]) are defined only for the default `std::vec::Vec`
+    /// backing rather than generically over [`Backing`]. Rust's type parameter defaults don't
+    /// participate in inference, so a generic `fn new<B: Backing<T>>() -> Vec<T, B>` would leave
+    /// `B` ambiguous at every unannotated call site (including the `unempty::vec!` macro). An
+    /// instance backed by something other than `std::vec::Vec` can still be produced, via
+    /// [`TryFrom`].
     ///
     /// # Examples
     ///
@@ -72,7 +91,7 @@ impl<T> Vec<T> {
     /// Capacity is in two parts: the guaranteed portion of this data structure consumes 1 "capacity",
     /// and the dynamic portion of this data structure consumes the rest (the "additional capacity").
     ///
-    /// "Additional capacity" follows the same rules as [`std::vec::Vec`]:
+    /// "Additional capacity" follows the same rules as the backing store's own `with_capacity`:
     ///
     /// The vector will be able to hold at least additional capacity elements without reallocating.
     /// This method is allowed to allocate for more elements than capacity.
@@ -84,7 +103,8 @@ impl<T> Vec<T> {
     ///
     /// If it is imporant to know the exact allocated capacity, always use the `capacity` method after construction.
     ///
-    /// When `T` is a zero-sized type, there will be no allocation and the additional capacity will always be `usize::MAX`.
+    /// Like [`Vec::new`], this is only defined for the default backing; see that method's docs
+    /// for why.
     ///
     /// # Panics
     ///
@@ -99,7 +119,12 @@ impl<T> Vec<T> {
         let dynamic = std::vec::Vec::with_capacity(capacity.dynamic());
         Self { first, dynamic }
     }
+}
 
+impl<T, B> Vec<T, B>
+where
+    B: Backing<T>,
+{
     /// Returns the number of elements the `Vec` can hold without reallocating.
     ///
     /// # Examples
@@ -138,6 +163,77 @@ impl<T> Vec<T> {
         false
     }
 
+    /// Returns a reference to the first element.
+    ///
+    /// Since an `unempty::Vec` is guaranteed to never be empty, this is always available and
+    /// does not need to return an `Option`.
+    ///
+    /// # Examples
+    /// ```
+    /// let v = unempty::vec![1, 2, 3];
+    /// assert_eq!(v.first(), &1);
+    /// ```
+    pub fn first(&self) -> &T {
+        &self.first
+    }
+
+    /// Returns a mutable reference to the first element.
+    ///
+    /// Since an `unempty::Vec` is guaranteed to never be empty, this is always available and
+    /// does not need to return an `Option`.
+    ///
+    /// # Examples
+    /// ```
+    /// let mut v = unempty::vec![1, 2, 3];
+    /// *v.first_mut() = 10;
+    /// assert_eq!(v.first(), &10);
+    /// ```
+    pub fn first_mut(&mut self) -> &mut T {
+        &mut self.first
+    }
+
+    /// Returns a reference to the last element.
+    ///
+    /// Since an `unempty::Vec` is guaranteed to never be empty, this is always available and
+    /// does not need to return an `Option`.
+    ///
+    /// # Examples
+    /// ```
+    /// let v = unempty::vec![1, 2, 3];
+    /// assert_eq!(v.last(), &3);
+    /// ```
+    pub fn last(&self) -> &T {
+        let len = self.dynamic.len();
+        if len == 0 {
+            &self.first
+        } else {
+            self.dynamic.get(len - 1).unwrap_or(&self.first)
+        }
+    }
+
+    /// Returns a mutable reference to the last element.
+    ///
+    /// Since an `unempty::Vec` is guaranteed to never be empty, this is always available and
+    /// does not need to return an `Option`.
+    ///
+    /// # Examples
+    /// ```
+    /// let mut v = unempty::vec![1, 2, 3];
+    /// *v.last_mut() = 30;
+    /// assert_eq!(v.last(), &30);
+    /// ```
+    pub fn last_mut(&mut self) -> &mut T {
+        let len = self.dynamic.len();
+        if len == 0 {
+            &mut self.first
+        } else {
+            match self.dynamic.get_mut(len - 1) {
+                Some(item) => item,
+                None => &mut self.first,
+            }
+        }
+    }
+
     /// Removes the last element from a vector and returns it.
     ///
     /// If you’d like to pop the first element, consider using `VecDeque::pop_front` instead.
@@ -188,16 +284,297 @@ impl<T> Vec<T> {
     pub fn push(&mut self, item: T) {
         self.dynamic.push(item);
     }
+
+    /// Returns an iterator over references to the elements of the vector, first element first.
+    ///
+    /// # Examples
+    /// ```
+    /// let v = unempty::vec![1, 2, 3];
+    /// let mut iter = v.iter();
+    /// assert_eq!(iter.next(), Some(&1));
+    /// assert_eq!(iter.next(), Some(&2));
+    /// ```
+    pub fn iter(&self) -> NonEmptyIter<&T, B::Iter<'_>> {
+        NonEmptyIter::new(&self.first, self.dynamic.iter())
+    }
+
+    /// Returns an iterator over mutable references to the elements of the vector, first element first.
+    ///
+    /// # Examples
+    /// ```
+    /// let mut v = unempty::vec![1, 2, 3];
+    /// v.iter_mut().for_each(|item| *item += 1);
+    /// assert_eq!(v, unempty::vec![2, 3, 4]);
+    /// ```
+    pub fn iter_mut(&mut self) -> NonEmptyIter<&mut T, B::IterMut<'_>> {
+        NonEmptyIter::new(&mut self.first, self.dynamic.iter_mut())
+    }
+
+    /// Inserts an element at position `index`, shifting all elements after it to the right.
+    ///
+    /// Inserting at index 0 moves the current first element into the dynamic portion and
+    /// installs `item` as the new first element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    ///
+    /// # Examples
+    /// ```
+    /// let mut vec = unempty::vec![1, 2, 3];
+    /// vec.insert(1, 10);
+    /// assert_eq!(vec, unempty::vec![1, 10, 2, 3]);
+    /// ```
+    pub fn insert(&mut self, index: usize, item: T) {
+        let len = self.len();
+        assert!(
+            index <= len,
+            "insertion index (is {index}) should be <= len (is {len})"
+        );
+        if index == 0 {
+            let old_first = std::mem::replace(&mut self.first, item);
+            self.dynamic.insert(0, old_first);
+        } else {
+            self.dynamic.insert(index - 1, item);
+        }
+    }
+
+    /// Removes and returns the element at position `index`, shifting all elements after it to the left.
+    ///
+    /// Removing index 0 pulls the next dynamically stored element up into the first slot, which
+    /// (like std's `remove`) costs `O(n)`.
+    ///
+    /// # Consuming self
+    ///
+    /// Since this method may remove the only remaining item, it consumes the vector and
+    /// optionally returns the vector with its new size, following the same pattern as [`Vec::pop`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index >= len`.
+    ///
+    /// # Examples
+    /// ```
+    /// let vec = unempty::vec![1, 2, 3];
+    /// let (vec, removed) = vec.remove(0);
+    /// assert_eq!(removed, 1);
+    /// assert_eq!(vec, Some(unempty::vec![2, 3]));
+    /// ```
+    pub fn remove(mut self, index: usize) -> (Option<Self>, T) {
+        let len = self.len();
+        assert!(
+            index < len,
+            "removal index (is {index}) should be < len (is {len})"
+        );
+        if index == 0 {
+            if self.dynamic.is_empty() {
+                (None, self.first)
+            } else {
+                let new_first = self.dynamic.remove(0);
+                let old_first = std::mem::replace(&mut self.first, new_first);
+                (Some(self), old_first)
+            }
+        } else {
+            let item = self.dynamic.remove(index - 1);
+            (Some(self), item)
+        }
+    }
+
+    /// Removes and returns the element at position `index`, replacing it with the last element.
+    ///
+    /// Unlike [`Vec::remove`], this does not shift the remaining elements, so it is `O(1)` instead
+    /// of `O(n)`. Removing index 0 promotes the last element into the first slot.
+    ///
+    /// # Consuming self
+    ///
+    /// Since this method may remove the only remaining item, it consumes the vector and
+    /// optionally returns the vector with its new size, following the same pattern as [`Vec::pop`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index >= len`.
+    ///
+    /// # Examples
+    /// ```
+    /// let vec = unempty::vec![1, 2, 3];
+    /// let (vec, removed) = vec.swap_remove(0);
+    /// assert_eq!(removed, 1);
+    /// assert_eq!(vec, Some(unempty::vec![3, 2]));
+    /// ```
+    pub fn swap_remove(mut self, index: usize) -> (Option<Self>, T) {
+        let len = self.len();
+        assert!(
+            index < len,
+            "removal index (is {index}) should be < len (is {len})"
+        );
+        if index == 0 {
+            match self.dynamic.pop() {
+                Some(new_first) => {
+                    let old_first = std::mem::replace(&mut self.first, new_first);
+                    (Some(self), old_first)
+                }
+                None => (None, self.first),
+            }
+        } else {
+            let item = self.dynamic.swap_remove(index - 1);
+            (Some(self), item)
+        }
+    }
+
+    /// Retains only the elements for which `keep` returns `true`, consuming `self`.
+    ///
+    /// # Consuming self
+    ///
+    /// Since retaining can remove every element, this consumes the vector and returns `None`
+    /// if no elements survive, following the same pattern as [`Vec::pop`].
+    ///
+    /// # Examples
+    /// ```
+    /// let vec = unempty::vec![1, 2, 3, 4];
+    /// let vec = vec.retain(|item| item % 2 == 0);
+    /// assert_eq!(vec, Some(unempty::vec![2, 4]));
+    /// ```
+    pub fn retain<F>(mut self, mut keep: F) -> Option<Self>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let keep_first = keep(&self.first);
+        self.dynamic.retain(|item| keep(item));
+        if keep_first {
+            Some(self)
+        } else if self.dynamic.is_empty() {
+            None
+        } else {
+            self.first = self.dynamic.remove(0);
+            Some(self)
+        }
+    }
+
+    /// Shortens the vector, keeping the first `len` elements and dropping the rest.
+    ///
+    /// If `len` is greater than or equal to the vector's current length, this has no effect.
+    ///
+    /// # Consuming self
+    ///
+    /// Since truncating can remove every element (e.g. `truncate(0)`), this consumes the vector
+    /// and returns `None` if no elements remain, following the same pattern as [`Vec::pop`].
+    /// `truncate(1)` keeps only the statically stored first element.
+    ///
+    /// # Examples
+    /// ```
+    /// let vec = unempty::vec![1, 2, 3];
+    /// assert_eq!(vec.clone().truncate(1), Some(unempty::vec![1]));
+    /// assert_eq!(vec.truncate(0), None);
+    /// ```
+    pub fn truncate(mut self, len: usize) -> Option<Self> {
+        if len == 0 {
+            None
+        } else {
+            self.dynamic.truncate(len - 1);
+            Some(self)
+        }
+    }
+
+    /// Removes the elements in `range`, returning them as a `std::vec::Vec<T>`.
+    ///
+    /// # Consuming self
+    ///
+    /// Since draining can remove every element, this consumes the vector and returns `None` in
+    /// the first tuple position if no elements remain afterward, following the same pattern as
+    /// [`Vec::pop`].
+    ///
+    /// # Examples
+    /// ```
+    /// let vec = unempty::vec![1, 2, 3, 4];
+    /// let (vec, drained) = vec.drain(1..3);
+    /// assert_eq!(drained, std::vec::Vec::from([2, 3]));
+    /// assert_eq!(vec, Some(unempty::vec![1, 4]));
+    /// ```
+    pub fn drain<R>(self, range: R) -> (Option<Self>, std::vec::Vec<T>)
+    where
+        R: RangeBounds<usize>,
+    {
+        let mut items: std::vec::Vec<T> = self.into();
+        let drained = items.drain(range).collect();
+        (Self::try_from(items).ok(), drained)
+    }
+}
+
+impl<T> Vec<T, std::vec::Vec<T>>
+where
+    T: Clone,
+{
+    /// Constructs a new instance by cloning `elem` `n` times.
+    ///
+    /// Because the non-empty guarantee requires at least one element, `n` is a [`NonZeroUsize`].
+    /// Bulk-clones the dynamic portion rather than pushing in a loop.
+    ///
+    /// [`NonZeroUsize`]: std::num::NonZeroUsize
+    ///
+    /// # Examples
+    /// ```
+    /// # use std::num::NonZeroUsize;
+    /// let v = unempty::Vec::from_elem("x", NonZeroUsize::new(3).expect("non-zero"));
+    /// assert_eq!(v, unempty::vec!["x", "x", "x"]);
+    /// ```
+    pub fn from_elem(elem: T, n: std::num::NonZeroUsize) -> Self {
+        let n = n.get();
+        let first = elem.clone();
+        let dynamic = std::iter::repeat_n(elem, n - 1).collect();
+        Self { first, dynamic }
+    }
+}
+
+impl<T, B> IntoIterator for Vec<T, B>
+where
+    B: Backing<T>,
+{
+    type Item = T;
+    type IntoIter = NonEmptyIter<T, B::IntoIter>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        NonEmptyIter::new(self.first, self.dynamic.into_iter())
+    }
+}
+
+impl<'a, T, B> IntoIterator for &'a Vec<T, B>
+where
+    B: Backing<T>,
+{
+    type Item = &'a T;
+    type IntoIter = NonEmptyIter<&'a T, B::Iter<'a>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, B> IntoIterator for &'a mut Vec<T, B>
+where
+    B: Backing<T>,
+{
+    type Item = &'a mut T;
+    type IntoIter = NonEmptyIter<&'a mut T, B::IterMut<'a>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
 }
 
-impl<T> TryFrom<std::vec::Vec<T>> for Vec<T> {
+impl<T, B> TryFrom<std::vec::Vec<T>> for Vec<T, B>
+where
+    B: Backing<T>,
+{
     type Error = TryFromError;
 
     fn try_from(sv: std::vec::Vec<T>) -> Result<Self, Self::Error> {
         let mut sv = VecDeque::from(sv);
         if let Some(first) = sv.pop_front() {
-            let mut v = Self::new(first);
-            v.extend(sv.into_iter());
+            let mut v = Self {
+                first,
+                dynamic: Default::default(),
+            };
+            v.extend(sv);
             Ok(v)
         } else {
             Err(TryFromError::SourceEmpty)
@@ -205,13 +582,19 @@ impl<T> TryFrom<std::vec::Vec<T>> for Vec<T> {
     }
 }
 
-impl<T> TryFrom<VecDeque<T>> for Vec<T> {
+impl<T, B> TryFrom<VecDeque<T>> for Vec<T, B>
+where
+    B: Backing<T>,
+{
     type Error = TryFromError;
 
     fn try_from(mut sv: VecDeque<T>) -> Result<Self, Self::Error> {
         if let Some(first) = sv.pop_front() {
-            let mut v = Self::new(first);
-            v.extend(sv.into_iter());
+            let mut v = Self {
+                first,
+                dynamic: Default::default(),
+            };
+            v.extend(sv);
             Ok(v)
         } else {
             Err(TryFromError::SourceEmpty)
@@ -219,48 +602,65 @@ impl<T> TryFrom<VecDeque<T>> for Vec<T> {
     }
 }
 
-impl<T> From<Vec<T>> for std::vec::Vec<T> {
-    fn from(sv: Vec<T>) -> Self {
+impl<T, B> From<Vec<T, B>> for std::vec::Vec<T>
+where
+    B: Backing<T>,
+{
+    fn from(sv: Vec<T, B>) -> Self {
         let mut v = std::vec::Vec::with_capacity(sv.len());
         v.push(sv.first);
-        v.extend(sv.dynamic.into_iter());
+        v.extend(sv.dynamic);
         v
     }
 }
 
-impl<T> From<Vec<T>> for VecDeque<T> {
-    fn from(sv: Vec<T>) -> Self {
+impl<T, B> From<Vec<T, B>> for VecDeque<T>
+where
+    B: Backing<T>,
+{
+    fn from(sv: Vec<T, B>) -> Self {
         let mut v = VecDeque::with_capacity(sv.len());
         v.push_back(sv.first);
-        v.extend(sv.dynamic.into_iter());
+        v.extend(sv.dynamic);
         v
     }
 }
 
-impl<T> Extend<T> for Vec<T> {
+impl<T, B> Extend<T> for Vec<T, B>
+where
+    B: Backing<T>,
+{
     fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
         self.dynamic.extend(iter);
     }
 }
 
-impl<T> Index<usize> for Vec<T> {
+impl<T, B> Index<usize> for Vec<T, B>
+where
+    B: Backing<T>,
+{
     type Output = T;
 
     fn index(&self, index: usize) -> &Self::Output {
         if index == 0 {
             &self.first
         } else {
-            &self.dynamic[index - 1]
+            self.dynamic.get(index - 1).expect("index out of bounds")
         }
     }
 }
 
-impl<T> IndexMut<usize> for Vec<T> {
+impl<T, B> IndexMut<usize> for Vec<T, B>
+where
+    B: Backing<T>,
+{
     fn index_mut(&mut self, index: usize) -> &mut Self::Output {
         if index == 0 {
             &mut self.first
         } else {
-            &mut self.dynamic[index - 1]
+            self.dynamic
+                .get_mut(index - 1)
+                .expect("index out of bounds")
         }
     }
 }
@@ -0,0 +1,132 @@
+use std::collections::VecDeque;
+use unempty::Backing;
+
+/// A minimal `Backing` implementation defined outside the crate, proving the trait is genuinely
+/// implementable by downstream code (e.g. a `SmallVec` or arena-backed store), not just usable
+/// via the crate's own blanket impls for `std::vec::Vec`/`VecDeque`.
+#[derive(Default)]
+struct ExternalVec<T>(std::vec::Vec<T>);
+
+impl<T> Extend<T> for ExternalVec<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.0.extend(iter);
+    }
+}
+
+impl<T> IntoIterator for ExternalVec<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<T> Backing<T> for ExternalVec<T> {
+    type Iter<'a>
+        = std::slice::Iter<'a, T>
+    where
+        T: 'a;
+    type IterMut<'a>
+        = std::slice::IterMut<'a, T>
+    where
+        T: 'a;
+
+    fn with_capacity(capacity: usize) -> Self {
+        Self(std::vec::Vec::with_capacity(capacity))
+    }
+
+    fn capacity(&self) -> usize {
+        self.0.capacity()
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn push(&mut self, item: T) {
+        self.0.push(item);
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        self.0.pop()
+    }
+
+    fn get(&self, index: usize) -> Option<&T> {
+        self.0.get(index)
+    }
+
+    fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.0.get_mut(index)
+    }
+
+    fn insert(&mut self, index: usize, item: T) {
+        self.0.insert(index, item);
+    }
+
+    fn remove(&mut self, index: usize) -> T {
+        self.0.remove(index)
+    }
+
+    fn swap_remove(&mut self, index: usize) -> T {
+        self.0.swap_remove(index)
+    }
+
+    fn retain<F: FnMut(&T) -> bool>(&mut self, keep: F) {
+        self.0.retain(keep);
+    }
+
+    fn truncate(&mut self, len: usize) {
+        self.0.truncate(len);
+    }
+
+    fn iter(&self) -> Self::Iter<'_> {
+        self.0.iter()
+    }
+
+    fn iter_mut(&mut self) -> Self::IterMut<'_> {
+        self.0.iter_mut()
+    }
+}
+
+#[test]
+fn vec_with_hand_rolled_external_backing_supports_the_full_api() {
+    let mut v: unempty::Vec<i32, ExternalVec<i32>> =
+        unempty::Vec::try_from(vec![1, 2, 3]).expect("source is non-empty");
+
+    v.push(4);
+    v.insert(0, 0);
+    assert_eq!(
+        v.iter().copied().collect::<std::vec::Vec<_>>(),
+        vec![0, 1, 2, 3, 4]
+    );
+
+    let (v, removed) = v.remove(0);
+    assert_eq!(removed, 0);
+    let v = v.expect("items remain");
+    assert_eq!(
+        v.iter().copied().collect::<std::vec::Vec<_>>(),
+        vec![1, 2, 3, 4]
+    );
+}
+
+#[test]
+fn vec_with_vecdeque_backing_supports_the_full_api() {
+    let mut v: unempty::Vec<i32, VecDeque<i32>> =
+        unempty::Vec::try_from(vec![1, 2, 3]).expect("source is non-empty");
+
+    v.push(4);
+    v.insert(0, 0);
+    assert_eq!(
+        v.iter().copied().collect::<std::vec::Vec<_>>(),
+        vec![0, 1, 2, 3, 4]
+    );
+
+    let (v, removed) = v.remove(0);
+    assert_eq!(removed, 0);
+    let v = v.expect("items remain");
+    assert_eq!(
+        v.iter().copied().collect::<std::vec::Vec<_>>(),
+        vec![1, 2, 3, 4]
+    );
+}
@@ -0,0 +1,33 @@
+#[test]
+fn insert_and_get_round_trip() {
+    let mut map = unempty::HashMap::new("a", 1);
+    assert_eq!(map.insert("b", 2), None);
+    assert_eq!(map.get(&"a"), Some(&1));
+    assert_eq!(map.get(&"b"), Some(&2));
+    assert_eq!(map.get(&"c"), None);
+}
+
+#[test]
+fn insert_over_first_key_replaces_value() {
+    let mut map = unempty::HashMap::new("a", 1);
+    assert_eq!(map.insert("a", 2), Some(1));
+    assert_eq!(map.get(&"a"), Some(&2));
+}
+
+#[test]
+fn remove_first_key_promotes_another_pair() {
+    let mut map = unempty::HashMap::new("a", 1);
+    map.insert("b", 2);
+    let (map, removed) = map.remove(&"a");
+    assert_eq!(removed, Some(1));
+    let map = map.expect("one pair remains");
+    assert_eq!(map.get(&"b"), Some(&2));
+}
+
+#[test]
+fn remove_last_pair_empties_the_map() {
+    let map = unempty::HashMap::new("a", 1);
+    let (map, removed) = map.remove(&"a");
+    assert_eq!(removed, Some(1));
+    assert_eq!(map, None);
+}
@@ -0,0 +1,27 @@
+#[test]
+fn insert_and_contains_round_trip() {
+    let mut set = unempty::HashSet::new("a");
+    assert!(set.insert("b"));
+    assert!(!set.insert("a"));
+    assert!(set.contains(&"a"));
+    assert!(set.contains(&"b"));
+    assert!(!set.contains(&"c"));
+}
+
+#[test]
+fn remove_first_item_promotes_another() {
+    let mut set = unempty::HashSet::new("a");
+    set.insert("b");
+    let (set, removed) = set.remove(&"a");
+    assert!(removed);
+    let set = set.expect("one item remains");
+    assert!(set.contains(&"b"));
+}
+
+#[test]
+fn remove_last_item_empties_the_set() {
+    let set = unempty::HashSet::new("a");
+    let (set, removed) = set.remove(&"a");
+    assert!(removed);
+    assert_eq!(set, None);
+}
@@ -0,0 +1,4 @@
+mod backing;
+mod hashmap;
+mod hashset;
+mod vec;
@@ -10,3 +10,119 @@ fn compiles_but_panics_slice_oob() {
     let v = unempty::Vec::new("abcd");
     let _ = v[1];
 }
+
+#[test]
+fn iter_visits_first_then_dynamic_items() {
+    let v = unempty::vec![1, 2, 3];
+    let items: std::vec::Vec<_> = v.iter().collect();
+    assert_eq!(items, std::vec::Vec::from([&1, &2, &3]));
+}
+
+#[test]
+fn into_iter_by_ref_matches_iter() {
+    let v = unempty::vec![1, 2, 3];
+    let items: std::vec::Vec<_> = (&v).into_iter().collect();
+    assert_eq!(items, std::vec::Vec::from([&1, &2, &3]));
+}
+
+#[test]
+fn into_iter_owned_yields_all_items() {
+    let v = unempty::vec![1, 2, 3];
+    let items: std::vec::Vec<_> = v.into_iter().collect();
+    assert_eq!(items, std::vec::Vec::from([1, 2, 3]));
+}
+
+#[test]
+fn first_and_last_on_single_item_vec() {
+    let v = unempty::Vec::new(1);
+    assert_eq!(v.first(), &1);
+    assert_eq!(v.last(), &1);
+}
+
+#[test]
+fn first_and_last_mut_update_in_place() {
+    let mut v = unempty::vec![1, 2, 3];
+    *v.first_mut() = 10;
+    *v.last_mut() = 30;
+    assert_eq!(v, unempty::vec![10, 2, 30]);
+}
+
+#[test]
+fn insert_at_front_shifts_old_first_into_dynamic() {
+    let mut v = unempty::vec![1, 2, 3];
+    v.insert(0, 0);
+    assert_eq!(v, unempty::vec![0, 1, 2, 3]);
+}
+
+#[test]
+fn remove_at_front_promotes_next_item() {
+    let v = unempty::vec![1, 2, 3];
+    let (v, removed) = v.remove(0);
+    assert_eq!(removed, 1);
+    assert_eq!(v, Some(unempty::vec![2, 3]));
+}
+
+#[test]
+fn remove_last_item_empties_the_vec() {
+    let v = unempty::Vec::new(1);
+    let (v, removed) = v.remove(0);
+    assert_eq!(removed, 1);
+    assert_eq!(v, None);
+}
+
+#[test]
+fn swap_remove_at_front_promotes_last_item() {
+    let v = unempty::vec![1, 2, 3];
+    let (v, removed) = v.swap_remove(0);
+    assert_eq!(removed, 1);
+    assert_eq!(v, Some(unempty::vec![3, 2]));
+}
+
+#[test]
+fn retain_drops_first_and_promotes_next_survivor() {
+    let v = unempty::vec![1, 2, 3, 4];
+    let v = v.retain(|item| item % 2 == 0);
+    assert_eq!(v, Some(unempty::vec![2, 4]));
+}
+
+#[test]
+fn retain_removing_everything_empties_the_vec() {
+    let v = unempty::vec![1, 2, 3];
+    let v = v.retain(|_| false);
+    assert_eq!(v, None);
+}
+
+#[test]
+fn truncate_keeps_only_first_n_items() {
+    let v = unempty::vec![1, 2, 3];
+    assert_eq!(v.clone().truncate(1), Some(unempty::vec![1]));
+    assert_eq!(v.truncate(0), None);
+}
+
+#[test]
+fn drain_removes_a_middle_range() {
+    let v = unempty::vec![1, 2, 3, 4];
+    let (v, drained) = v.drain(1..3);
+    assert_eq!(drained, std::vec::Vec::from([2, 3]));
+    assert_eq!(v, Some(unempty::vec![1, 4]));
+}
+
+#[test]
+fn iter_mut_updates_every_item() {
+    let mut v = unempty::vec![1, 2, 3];
+    v.iter_mut().for_each(|item| *item += 1);
+    assert_eq!(v, unempty::vec![2, 3, 4]);
+}
+
+#[test]
+fn vec_macro_repeat_form_clones_elem_n_times() {
+    let v = unempty::vec![0; 3];
+    assert_eq!(v, unempty::vec![0, 0, 0]);
+}
+
+#[test]
+fn from_elem_matches_repeat_form_macro() {
+    let n = std::num::NonZeroUsize::new(3).expect("non-zero");
+    let v = unempty::Vec::from_elem("x", n);
+    assert_eq!(v, unempty::vec!["x", "x", "x"]);
+}